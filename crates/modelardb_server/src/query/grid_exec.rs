@@ -18,6 +18,7 @@
 //! compressed segments containing metadata and models.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::fmt::{self, Formatter};
 use std::pin::Pin;
 use std::sync::Arc;
@@ -33,13 +34,18 @@ use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::common::cast::as_boolean_array;
 use datafusion::error::{DataFusionError, Result};
 use datafusion::execution::context::TaskContext;
+use datafusion::logical_expr::Operator;
 use datafusion::physical_expr::{EquivalenceProperties, PhysicalSortRequirement};
-use datafusion::physical_plan::expressions::PhysicalSortExpr;
-use datafusion::physical_plan::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use datafusion::physical_plan::common::spawn_buffered;
+use datafusion::physical_plan::expressions::{BinaryExpr, Column, Literal, PhysicalSortExpr};
+use datafusion::physical_plan::metrics::{
+    BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
+};
 use datafusion::physical_plan::{
     DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, Partitioning, PhysicalExpr,
     RecordBatchStream, SendableRecordBatchStream, Statistics,
 };
+use datafusion::scalar::ScalarValue;
 use futures::stream::{Stream, StreamExt};
 use modelardb_common::schemas::QUERY_SCHEMA;
 use modelardb_common::types::{TimestampArray, TimestampBuilder, ValueArray, ValueBuilder};
@@ -60,6 +66,13 @@ pub struct GridExec {
     limit: Option<usize>,
     /// Execution plan to read batches of segments from.
     input: Arc<dyn ExecutionPlan>,
+    /// If `true`, `input` is allowed to have more than one partition and each partition is
+    /// reconstructed by its own [`GridStream`] running in parallel, with the results merged back
+    /// into the single globally sorted stream [`crate::query::sorted_join_exec::SortedJoinExec`]
+    /// assumes. Set from the `reconstruct_segments_in_parallel` session config flag by the physical
+    /// optimizer rule that creates this [`GridExec`] so the existing single-partition behavior
+    /// remains the default.
+    reconstruct_in_parallel: bool,
     /// Metrics collected during execution for use by EXPLAIN ANALYZE.
     metrics: ExecutionPlanMetricsSet,
 }
@@ -69,6 +82,7 @@ impl GridExec {
         maybe_predicate: Option<Arc<dyn PhysicalExpr>>,
         limit: Option<usize>,
         input: Arc<dyn ExecutionPlan>,
+        reconstruct_in_parallel: bool,
     ) -> Arc<Self> {
         let schema = QUERY_SCHEMA.0.clone();
 
@@ -77,6 +91,7 @@ impl GridExec {
             schema,
             limit,
             input,
+            reconstruct_in_parallel,
             metrics: ExecutionPlanMetricsSet::new(),
         })
     }
@@ -94,9 +109,15 @@ impl ExecutionPlan for GridExec {
         self.schema.clone()
     }
 
-    /// Return the partitioning of the single execution plan batches of segments are read from.
+    /// Return the partitioning of the execution plan batches of segments are read from, or a
+    /// single partition if [`Self::reconstruct_in_parallel`] is `true` since the partitions
+    /// reconstructed in parallel are merged back into a single globally sorted stream.
     fn output_partitioning(&self) -> Partitioning {
-        self.input.output_partitioning()
+        if self.reconstruct_in_parallel {
+            Partitioning::UnknownPartitioning(1)
+        } else {
+            self.input.output_partitioning()
+        }
     }
 
     /// Specify that the global order for the data points produced by all [`GridExec`] will be the
@@ -123,6 +144,7 @@ impl ExecutionPlan for GridExec {
                 self.maybe_predicate.clone(),
                 self.limit,
                 children[0].clone(),
+                self.reconstruct_in_parallel,
             ))
         } else {
             Err(DataFusionError::Plan(format!(
@@ -133,7 +155,10 @@ impl ExecutionPlan for GridExec {
 
     /// Create a stream that reads batches of compressed segments from the child stream,
     /// reconstructs the data points from the metadata and models in the segments, and returns
-    /// batches of rows with data points.
+    /// batches of rows with data points. If [`Self::reconstruct_in_parallel`] is `true`, one
+    /// [`GridStream`] per partition of `input` is spawned onto its own task so reconstruction runs
+    /// in parallel, and their outputs are merged with a [`GridMergeStream`] into the single
+    /// globally sorted stream this operator always returns for `partition` `0`.
     fn execute(
         &self,
         partition: usize,
@@ -142,14 +167,45 @@ impl ExecutionPlan for GridExec {
         // Must be read before GridStream as task_context are moved into input.
         let batch_size = task_context.session_config().batch_size();
 
-        Ok(Box::pin(GridStream::new(
-            self.schema.clone(),
-            self.maybe_predicate.clone(),
-            self.limit,
-            self.input.execute(partition, task_context)?,
-            batch_size,
-            BaselineMetrics::new(&self.metrics, partition),
-        )))
+        if self.reconstruct_in_parallel {
+            let input_partitions = self.input.output_partitioning().partition_count();
+
+            let grid_streams = (0..input_partitions)
+                .map(|input_partition| {
+                    let grid_stream: SendableRecordBatchStream = Box::pin(GridStream::new(
+                        self.schema.clone(),
+                        self.maybe_predicate.clone(),
+                        self.limit,
+                        self.input.execute(input_partition, task_context.clone())?,
+                        batch_size,
+                        BaselineMetrics::new(&self.metrics, input_partition),
+                        GridMetrics::new(&self.metrics, input_partition),
+                    ));
+
+                    // Run each partition's reconstruction in its own task so it is CPU-scalable
+                    // instead of being serialized into a single stream.
+                    Ok(spawn_buffered(grid_stream, 1))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Box::pin(GridMergeStream::new(
+                self.schema.clone(),
+                grid_streams,
+                batch_size,
+                self.limit,
+                BaselineMetrics::new(&self.metrics, partition),
+            )))
+        } else {
+            Ok(Box::pin(GridStream::new(
+                self.schema.clone(),
+                self.maybe_predicate.clone(),
+                self.limit,
+                self.input.execute(partition, task_context)?,
+                batch_size,
+                BaselineMetrics::new(&self.metrics, partition),
+                GridMetrics::new(&self.metrics, partition),
+            )))
+        }
     }
 
     /// Specify that [`GridExec`] knows nothing about the data it will output.
@@ -157,11 +213,18 @@ impl ExecutionPlan for GridExec {
         Ok(Statistics::new_unknown(&self.schema))
     }
 
-    /// Specify that [`GridExec`] requires one partition for each input as it assumes that the
-    /// global sort order are the same for its input and Apache Arrow DataFusion only guarantees the
-    /// sort order within each partition rather than the input's global sort order.
+    /// Specify the distribution [`GridExec`] requires of its input. Unless
+    /// [`Self::reconstruct_in_parallel`] is `true`, this is one partition, as [`GridExec`] assumes
+    /// the global sort order is the same for its input and Apache Arrow DataFusion only guarantees
+    /// the sort order within each partition rather than the input's global sort order. When
+    /// reconstructing in parallel, each partition is instead reconstructed independently and
+    /// [`GridMergeStream`] restores the global sort order across partitions afterwards.
     fn required_input_distribution(&self) -> Vec<Distribution> {
-        vec![Distribution::SinglePartition]
+        if self.reconstruct_in_parallel {
+            vec![Distribution::UnspecifiedDistribution]
+        } else {
+            vec![Distribution::SinglePartition]
+        }
     }
 
     /// Specify that [`GridExec`] requires that its input provides data that is sorted by
@@ -190,10 +253,249 @@ impl DisplayAs for GridExec {
     /// Write a string-based representation of the operator to `f`. Returns
     /// `Err` if `std::write` cannot format the string and write it to `f`.
     fn fmt_as(&self, _t: DisplayFormatType, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "GridExec: limit={:?}", self.limit)
+        write!(
+            f,
+            "GridExec: limit={:?}, reconstruct_in_parallel={}",
+            self.limit, self.reconstruct_in_parallel
+        )
+    }
+}
+
+/// Number of segments and data points reconstructed for a single `model_type_id`, part of the
+/// breakdown in [`GridMetrics`].
+#[derive(Debug, Clone)]
+struct ModelTypeMetrics {
+    /// Number of segments with this `model_type_id` passed to `modelardb_compression::grid()`.
+    segments: Count,
+    /// Number of data points reconstructed from segments with this `model_type_id`.
+    data_points: Count,
+}
+
+/// Metrics collected by [`GridStream`] that are specific to reconstructing data points from
+/// compressed segments, i.e., in addition to [`BaselineMetrics`]. Mirrors how data-source execution
+/// plans expose `output_rows`/`bytes_processed` so the compression ratio and the model types
+/// dominating a query's cost can be seen in `EXPLAIN ANALYZE`.
+#[derive(Debug, Clone)]
+struct GridMetrics {
+    /// Number of segments skipped because the segment-level predicate pruning in
+    /// [`GridStream::grid_and_append_to_leftovers_in_current_batch`] proved they could not contain
+    /// any data points accepted by `maybe_predicate`.
+    pruned_segments: Count,
+    /// Number of segments passed to `modelardb_compression::grid()` to be reconstructed.
+    reconstructed_segments: Count,
+    /// Number of data points reconstructed from `reconstructed_segments`.
+    reconstructed_data_points: Count,
+    /// Number of bytes read from the compressed `residuals` and `values` `BinaryArray`s of the
+    /// segments in `reconstructed_segments`.
+    compressed_bytes_read: Count,
+    /// Breakdown of `reconstructed_segments` and `reconstructed_data_points` per `model_type_id`,
+    /// created lazily the first time a given `model_type_id` is reconstructed from.
+    by_model_type: HashMap<u8, ModelTypeMetrics>,
+    /// Kept so [`Self::record_reconstructed_segment`] can lazily register a new
+    /// [`ModelTypeMetrics`] in `by_model_type`.
+    metrics: ExecutionPlanMetricsSet,
+    /// Kept for the same reason as `metrics`.
+    partition: usize,
+}
+
+impl GridMetrics {
+    fn new(metrics: &ExecutionPlanMetricsSet, partition: usize) -> Self {
+        Self {
+            pruned_segments: MetricBuilder::new(metrics).counter("pruned_segments", partition),
+            reconstructed_segments: MetricBuilder::new(metrics)
+                .counter("reconstructed_segments", partition),
+            reconstructed_data_points: MetricBuilder::new(metrics)
+                .counter("reconstructed_data_points", partition),
+            compressed_bytes_read: MetricBuilder::new(metrics)
+                .counter("compressed_bytes_read", partition),
+            by_model_type: HashMap::new(),
+            metrics: metrics.clone(),
+            partition,
+        }
+    }
+
+    /// Record that a segment with `model_type_id` has been reconstructed into `data_points` data
+    /// points from `compressed_bytes` bytes of residuals and values.
+    fn record_reconstructed_segment(
+        &mut self,
+        model_type_id: u8,
+        data_points: usize,
+        compressed_bytes: usize,
+    ) {
+        self.reconstructed_segments.add(1);
+        self.reconstructed_data_points.add(data_points);
+        self.compressed_bytes_read.add(compressed_bytes);
+
+        let metrics = &self.metrics;
+        let partition = self.partition;
+        let model_type_metrics = self.by_model_type.entry(model_type_id).or_insert_with(|| {
+            let model_type_id = model_type_id.to_string();
+            ModelTypeMetrics {
+                segments: MetricBuilder::new(metrics)
+                    .with_new_label("model_type_id", model_type_id.clone())
+                    .counter("segments_by_model_type", partition),
+                data_points: MetricBuilder::new(metrics)
+                    .with_new_label("model_type_id", model_type_id)
+                    .counter("data_points_by_model_type", partition),
+            }
+        });
+
+        model_type_metrics.segments.add(1);
+        model_type_metrics.data_points.add(data_points);
     }
 }
 
+/// A conservative, inclusive lower and upper bound on the values a single column of
+/// [`QUERY_SCHEMA`] can take for `maybe_predicate` to accept a row, used to prune compressed
+/// segments before they are reconstructed. `None` means the corresponding bound is unconstrained.
+#[derive(Debug, Default, Clone, Copy)]
+struct ColumnRange {
+    lower: Option<f64>,
+    upper: Option<f64>,
+}
+
+impl ColumnRange {
+    /// Return `true` unless it can be proven that no value in `[segment_lower, segment_upper]` is
+    /// within this range, i.e., a `false` result guarantees the segment cannot contain a data
+    /// point `maybe_predicate` would accept.
+    fn may_overlap(&self, segment_lower: f64, segment_upper: f64) -> bool {
+        let satisfies_lower = self.lower.map_or(true, |lower| segment_upper >= lower);
+        let satisfies_upper = self.upper.map_or(true, |upper| segment_lower <= upper);
+        satisfies_lower && satisfies_upper
+    }
+
+    /// Narrow the lower bound to `value` if it is more restrictive than the current lower bound.
+    fn tighten_lower(&mut self, value: f64) {
+        self.lower = Some(self.lower.map_or(value, |lower| lower.max(value)));
+    }
+
+    /// Narrow the upper bound to `value` if it is more restrictive than the current upper bound.
+    fn tighten_upper(&mut self, value: f64) {
+        self.upper = Some(self.upper.map_or(value, |upper| upper.min(value)));
+    }
+}
+
+/// Decompose `predicate` into conservative per-column [`ColumnRange`]s for the `timestamp` and
+/// `value` columns of [`QUERY_SCHEMA`]. Only conjunctions of simple comparisons between one of
+/// these columns and a literal are recognized, e.g., `timestamp >= 100 AND value < 42.0`. Any other
+/// expression, e.g., a disjunction or a comparison between two columns, simply does not narrow the
+/// corresponding range, so the returned ranges are always safe to use for pruning.
+fn predicate_to_column_ranges(predicate: &Arc<dyn PhysicalExpr>) -> (ColumnRange, ColumnRange) {
+    let mut time_range = ColumnRange::default();
+    let mut value_range = ColumnRange::default();
+
+    for conjunct in split_conjunction(predicate) {
+        narrow_column_ranges(&conjunct, &mut time_range, &mut value_range);
+    }
+
+    (time_range, value_range)
+}
+
+/// Split `predicate` into the expressions conjoined by `AND`, or return `predicate` itself as the
+/// only element if it is not a conjunction.
+fn split_conjunction(predicate: &Arc<dyn PhysicalExpr>) -> Vec<Arc<dyn PhysicalExpr>> {
+    if let Some(binary_expr) = predicate.as_any().downcast_ref::<BinaryExpr>() {
+        if *binary_expr.op() == Operator::And {
+            let mut conjuncts = split_conjunction(binary_expr.left());
+            conjuncts.extend(split_conjunction(binary_expr.right()));
+            return conjuncts;
+        }
+    }
+
+    vec![predicate.clone()]
+}
+
+/// If `expr` is a comparison between a [`Column`] named `"timestamp"` or `"value"` and a
+/// [`Literal`], narrow `time_range` or `value_range` accordingly. Otherwise, `expr` is ignored.
+fn narrow_column_ranges(
+    expr: &Arc<dyn PhysicalExpr>,
+    time_range: &mut ColumnRange,
+    value_range: &mut ColumnRange,
+) {
+    let Some(binary_expr) = expr.as_any().downcast_ref::<BinaryExpr>() else {
+        return;
+    };
+
+    let (column, op, literal) = if let (Some(column), Some(literal)) = (
+        binary_expr.left().as_any().downcast_ref::<Column>(),
+        binary_expr.right().as_any().downcast_ref::<Literal>(),
+    ) {
+        (column, *binary_expr.op(), literal)
+    } else if let (Some(literal), Some(column)) = (
+        binary_expr.left().as_any().downcast_ref::<Literal>(),
+        binary_expr.right().as_any().downcast_ref::<Column>(),
+    ) {
+        (column, mirror_operator(*binary_expr.op()), literal)
+    } else {
+        return;
+    };
+
+    let Some(value) = scalar_value_to_f64(literal.value()) else {
+        return;
+    };
+
+    let range = match column.name() {
+        "timestamp" => time_range,
+        "value" => value_range,
+        _ => return,
+    };
+
+    match op {
+        Operator::Gt | Operator::GtEq => range.tighten_lower(value),
+        Operator::Lt | Operator::LtEq => range.tighten_upper(value),
+        Operator::Eq => {
+            range.tighten_lower(value);
+            range.tighten_upper(value);
+        }
+        _ => (),
+    }
+}
+
+/// Return the operator with the same meaning as `op` when its operands are swapped, e.g., `a > b`
+/// is equivalent to `b < a`.
+fn mirror_operator(op: Operator) -> Operator {
+    match op {
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        other => other,
+    }
+}
+
+/// Convert the numeric [`ScalarValue`] variants that can appear as a literal in `maybe_predicate`
+/// to `f64` so they can be compared to a segment's timestamps and values regardless of their exact
+/// Apache Arrow type. Returns [`None`] for variants that are not supported or are `NULL`.
+fn scalar_value_to_f64(scalar: &ScalarValue) -> Option<f64> {
+    match scalar {
+        ScalarValue::Float32(Some(value)) => Some(*value as f64),
+        ScalarValue::Float64(Some(value)) => Some(*value),
+        ScalarValue::Int64(Some(value)) => Some(*value as f64),
+        ScalarValue::UInt64(Some(value)) => Some(*value as f64),
+        ScalarValue::TimestampSecond(Some(value), _) => Some(*value as f64),
+        ScalarValue::TimestampMillisecond(Some(value), _) => Some(*value as f64),
+        ScalarValue::TimestampMicrosecond(Some(value), _) => Some(*value as f64),
+        ScalarValue::TimestampNanosecond(Some(value), _) => Some(*value as f64),
+        _ => None,
+    }
+}
+
+/// Return `true` if `accumulated_rows`, the number of data points already reconstructed or carried
+/// over from a previous batch, exceeds `remaining_fetch`, i.e., more data points are available than
+/// a pushed down limit can ever need. Used by [`GridStream`] to stop reconstructing data points
+/// when there is no `maybe_predicate` that could remove any of them. Returns `false` if no limit has
+/// been pushed down.
+fn reconstruction_budget_exhausted(accumulated_rows: usize, remaining_fetch: Option<usize>) -> bool {
+    remaining_fetch.is_some_and(|remaining| accumulated_rows > remaining)
+}
+
+/// Return `length` clamped to `remaining_fetch` if a limit has been pushed down, so a batch never
+/// contains more data points than are still requested. Used by both [`GridStream`] and
+/// [`GridMergeStream`] when slicing the batch of data points to return from `poll_next`.
+fn clamp_to_remaining_fetch(length: usize, remaining_fetch: Option<usize>) -> usize {
+    remaining_fetch.map_or(length, |remaining| usize::min(length, remaining))
+}
+
 /// A stream that read batches of rows with segments from the input stream, reconstructs the data
 /// points from the metadata and models in the segments, and returns batches of data points.
 struct GridStream {
@@ -201,16 +503,31 @@ struct GridStream {
     schema: SchemaRef,
     /// Predicate to filter data points by.
     maybe_predicate: Option<Arc<dyn PhysicalExpr>>,
+    /// Conservative range of the `timestamp` column that `maybe_predicate` can possibly accept,
+    /// derived once from `maybe_predicate` so segments entirely outside of it can be pruned before
+    /// `modelardb_compression::grid()` reconstructs their data points.
+    time_range: ColumnRange,
+    /// Conservative range of the `value` column that `maybe_predicate` can possibly accept,
+    /// derived once from `maybe_predicate` so segments entirely outside of it can be pruned before
+    /// `modelardb_compression::grid()` reconstructs their data points.
+    value_range: ColumnRange,
     /// Stream to read batches of compressed segments from.
     input: SendableRecordBatchStream,
     /// Size of the batches returned when this stream is pooled.
     batch_size: usize,
+    /// Number of data points that are still requested by the query. `GridExec::output_ordering()`
+    /// guarantees a global sort by [`QUERY_ORDER_DATA_POINT`], so once this reaches zero the
+    /// remaining input batches are known to only contain data points past the requested limit and
+    /// can be skipped entirely instead of being reconstructed.
+    remaining_fetch: Option<usize>,
     /// Current batch of data points to return data points from when the stream is pooled.
     current_batch: RecordBatch,
     /// Next data point in the current batch of data points to return when the stream is pooled.
     current_batch_offset: usize,
     /// Metrics collected during execution for use by EXPLAIN ANALYZE.
     baseline_metrics: BaselineMetrics,
+    /// Metrics specific to reconstructing data points from compressed segments.
+    grid_metrics: GridMetrics,
 }
 
 impl GridStream {
@@ -221,22 +538,33 @@ impl GridStream {
         input: SendableRecordBatchStream,
         batch_size: usize,
         baseline_metrics: BaselineMetrics,
+        grid_metrics: GridMetrics,
     ) -> Self {
-        // Assumes limit is mostly used to request less than batch_size rows so one batch is enough.
-        // If it is a bit larger than batch_size the second batch will contain too many data points.
-        // Also limit is not simply used as batch size to prevent OOM issues with a very big limit.
+        // limit is used to cap batch_size so a returned batch never holds more data points than
+        // the query requested. How many data points are reconstructed is bounded by
+        // remaining_fetch instead. limit is not simply used as batch_size to prevent OOM issues
+        // with a very big limit.
         let batch_size = if let Some(limit) = limit {
             usize::min(limit, batch_size)
         } else {
             batch_size
         };
 
+        let (time_range, value_range) = maybe_predicate
+            .as_ref()
+            .map(predicate_to_column_ranges)
+            .unwrap_or_default();
+
         GridStream {
             schema: schema.clone(),
             maybe_predicate,
+            time_range,
+            value_range,
             input,
             baseline_metrics,
+            grid_metrics,
             batch_size,
+            remaining_fetch: limit,
             current_batch: RecordBatch::new_empty(schema),
             current_batch_offset: 0,
         }
@@ -287,22 +615,60 @@ impl GridStream {
                 [self.current_batch_offset..],
         );
 
-        // Reconstruct the data points from the compressed segments.
+        // Reconstruct the data points from the compressed segments. If a limit has been pushed
+        // down and there is no predicate, stop as soon as more data points have been reconstructed
+        // than are still requested so huge segments near the limit boundary are not fully
+        // expanded. With a predicate, every segment in the batch is reconstructed, as the
+        // predicate is only evaluated once per batch below and the number of data points it keeps
+        // is not known until then.
         for row_index in 0..new_rows {
+            if self.maybe_predicate.is_none()
+                && reconstruction_budget_exhausted(univariate_id_builder.len(), self.remaining_fetch)
+            {
+                break;
+            }
+
+            let start_time = start_times.value(row_index);
+            let end_time = end_times.value(row_index);
+            let min_value = min_values.value(row_index);
+            let max_value = max_values.value(row_index);
+
+            // Skip segments that maybe_predicate provably cannot accept any data points from
+            // without reconstructing them. Segments that only partially overlap are still fully
+            // reconstructed and rely on the exact, per-data-point filtering below.
+            if !self.time_range.may_overlap(start_time as f64, end_time as f64)
+                || !self
+                    .value_range
+                    .may_overlap(min_value as f64, max_value as f64)
+            {
+                self.grid_metrics.pruned_segments.add(1);
+                continue;
+            }
+
+            let values_slice = values.value(row_index);
+            let residuals_slice = residuals.value(row_index);
+            let data_points_before = univariate_id_builder.len();
+
             modelardb_compression::grid(
                 univariate_ids.value(row_index),
                 model_type_ids.value(row_index),
-                start_times.value(row_index),
-                end_times.value(row_index),
+                start_time,
+                end_time,
                 timestamps.value(row_index),
-                min_values.value(row_index),
-                max_values.value(row_index),
-                values.value(row_index),
-                residuals.value(row_index),
+                min_value,
+                max_value,
+                values_slice,
+                residuals_slice,
                 &mut univariate_id_builder,
                 &mut timestamp_builder,
                 &mut value_builder,
             );
+
+            self.grid_metrics.record_reconstructed_segment(
+                model_type_ids.value(row_index),
+                univariate_id_builder.len() - data_points_before,
+                values_slice.len() + residuals_slice.len(),
+            );
         }
 
         let columns: Vec<ArrayRef> = vec![
@@ -342,6 +708,12 @@ impl Stream for GridStream {
         mut self: Pin<&mut Self>,
         cx: &mut StdTaskContext<'_>,
     ) -> Poll<Option<Self::Item>> {
+        // The pushed down limit has already been reached, so the remaining input batches are known
+        // to only contain data points past it and self.input is never polled again.
+        if self.remaining_fetch == Some(0) {
+            return self.baseline_metrics.record_poll(Poll::Ready(None));
+        }
+
         // Try to ensure there are enough data points in the current batch to match batch size.
         if (self.current_batch.num_rows() - self.current_batch_offset) < self.batch_size {
             match self.input.poll_next_unpin(cx) {
@@ -359,9 +731,18 @@ impl Stream for GridStream {
         // represent one data point, the current batch may not contain enough data points, e.g., if
         // the query contains a very specific predicate that filter out all but a very few segments.
         let remaining_data_points = self.current_batch.num_rows() - self.current_batch_offset;
-        let length = usize::min(self.batch_size, remaining_data_points);
+        let length = clamp_to_remaining_fetch(
+            usize::min(self.batch_size, remaining_data_points),
+            self.remaining_fetch,
+        );
+
         let batch = self.current_batch.slice(self.current_batch_offset, length);
         self.current_batch_offset += batch.num_rows();
+
+        if let Some(remaining_fetch) = self.remaining_fetch.as_mut() {
+            *remaining_fetch -= batch.num_rows();
+        }
+
         self.baseline_metrics
             .record_poll(Poll::Ready(Some(Ok(batch))))
     }
@@ -373,3 +754,581 @@ impl RecordBatchStream for GridStream {
         self.schema.clone()
     }
 }
+
+/// The part of a partition's data points that [`GridMergeStream`] has read into memory but not yet
+/// returned, together with the stream to read more data points from once it is exhausted.
+struct PartitionCursor {
+    /// Stream the data points reconstructed for this partition are read from.
+    stream: SendableRecordBatchStream,
+    /// Data points read from `stream` that have not yet been returned by [`GridMergeStream`].
+    batch: RecordBatch,
+    /// Next data point in `batch` to return.
+    offset: usize,
+    /// `true` once `stream` has returned [`Poll::Ready(None)`].
+    exhausted: bool,
+}
+
+/// A stream that merges the streams of data points reconstructed in parallel by one [`GridStream`]
+/// per partition of [`GridExec`]'s input back into a single stream sorted by `univariate_id` and
+/// then `timestamp`, i.e., the order [`QUERY_ORDER_DATA_POINT`] requires and the order a single,
+/// non-parallel [`GridStream`] would have produced had the input not been split into partitions.
+struct GridMergeStream {
+    /// Schema of the stream.
+    schema: SchemaRef,
+    /// One cursor per partition being merged.
+    cursors: Vec<PartitionCursor>,
+    /// Size of the batches returned when this stream is pooled.
+    batch_size: usize,
+    /// Number of data points that are still requested by the query, shared across every partition
+    /// being merged. Each partition's own [`GridStream`] is given the full, un-divided limit as its
+    /// own budget since it cannot know how many data points the other partitions will contribute,
+    /// but without this field [`GridMergeStream`] would simply concatenate all of their output,
+    /// letting up to `number_of_partitions * limit` data points be reconstructed for a single
+    /// `... LIMIT n` query. This field caps the total number of data points this stream emits to
+    /// the original limit, and dropping a partition's cursor once it is reached stops polling that
+    /// partition's stream, which in turn lets its `GridStream` stop reconstructing.
+    remaining_fetch: Option<usize>,
+    /// Metrics collected during execution for use by EXPLAIN ANALYZE. Each per-partition
+    /// `GridStream` has its own [`BaselineMetrics`] that records a row as output the moment it is
+    /// handed to a [`PartitionCursor`], which is not the same as this operator actually returning
+    /// it to [`crate::query::sorted_join_exec::SortedJoinExec`], e.g., a cursor's buffered but
+    /// unread rows are dropped and never reach this stream's output if the pushed down limit is
+    /// reached first. This field is what makes `output_rows`/`elapsed_compute` accurate for
+    /// [`GridExec`] as a whole rather than being the sum of its inputs' own accounting.
+    baseline_metrics: BaselineMetrics,
+}
+
+impl GridMergeStream {
+    fn new(
+        schema: SchemaRef,
+        streams: Vec<SendableRecordBatchStream>,
+        batch_size: usize,
+        limit: Option<usize>,
+        baseline_metrics: BaselineMetrics,
+    ) -> Self {
+        let cursors = streams
+            .into_iter()
+            .map(|stream| PartitionCursor {
+                stream,
+                batch: RecordBatch::new_empty(schema.clone()),
+                offset: 0,
+                exhausted: false,
+            })
+            .collect();
+
+        GridMergeStream {
+            schema,
+            cursors,
+            batch_size,
+            remaining_fetch: limit,
+            baseline_metrics,
+        }
+    }
+
+    /// Return the `(univariate_id, timestamp)` key of the next, not yet returned data point in
+    /// `batch`, used to determine which partition currently has the smallest data point.
+    fn merge_key(batch: &RecordBatch, row: usize) -> (u64, i64) {
+        let univariate_ids = modelardb_common::array!(batch, 0, UInt64Array);
+        let timestamps = modelardb_common::array!(batch, 1, TimestampArray);
+        (univariate_ids.value(row), timestamps.value(row))
+    }
+
+    /// Refill `cursor` from `cursor.stream` if it has returned every data point in `cursor.batch`
+    /// and is not yet exhausted. Empty batches are skipped, so after `Poll::Ready(Ok(()))` the
+    /// cursor either has a buffered data point or is exhausted. Returns `Poll::Pending` if
+    /// `cursor.stream` is not yet ready, otherwise `Poll::Ready(Ok(()))` or `Poll::Ready(Err(_))`
+    /// if it returns an error.
+    fn poll_refill_cursor(
+        cursor: &mut PartitionCursor,
+        cx: &mut StdTaskContext<'_>,
+    ) -> Poll<Result<()>> {
+        if cursor.exhausted || cursor.offset < cursor.batch.num_rows() {
+            return Poll::Ready(Ok(()));
+        }
+
+        loop {
+            match cursor.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(batch))) if batch.num_rows() == 0 => (),
+                Poll::Ready(Some(Ok(batch))) => {
+                    cursor.batch = batch;
+                    cursor.offset = 0;
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Err(error)),
+                Poll::Ready(None) => {
+                    cursor.exhausted = true;
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Stream for GridMergeStream {
+    /// Specify that [`GridMergeStream`] returns [`Result<RecordBatch>`] when polled.
+    type Item = Result<RecordBatch>;
+
+    /// Try to poll the next batch of data points, merged from the partitions being reconstructed in
+    /// parallel in `univariate_id`/`timestamp` order, and returns:
+    /// * `Poll::Pending` if a partition without any buffered data points is not yet ready.
+    /// * `Poll::Ready(Some(Ok(batch)))` if the next batch is ready.
+    /// * `Poll::Ready(None)` if every partition is exhausted.
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut StdTaskContext<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // The pushed down limit has already been reached. Dropping every cursor here, rather than
+        // just returning early, drops each partition's stream, which signals the GridStream (and,
+        // when running in parallel, the task spawn_buffered runs it on) backing it to stop.
+        if self.remaining_fetch == Some(0) {
+            self.cursors.clear();
+            return self.baseline_metrics.record_poll(Poll::Ready(None));
+        }
+
+        // Every partition that is not exhausted must have a buffered data point before the true
+        // minimum across all partitions can be determined.
+        for cursor in &mut self.cursors {
+            match Self::poll_refill_cursor(cursor, cx) {
+                Poll::Ready(Ok(())) => (),
+                Poll::Ready(Err(error)) => {
+                    return self
+                        .baseline_metrics
+                        .record_poll(Poll::Ready(Some(Err(error))))
+                }
+                Poll::Pending => return self.baseline_metrics.record_poll(Poll::Pending),
+            }
+        }
+
+        if self.cursors.iter().all(|cursor| cursor.exhausted) {
+            return self.baseline_metrics.record_poll(Poll::Ready(None));
+        }
+
+        // Record the time elapsed from the timer is created to it is dropped.
+        let _timer = self.baseline_metrics.elapsed_compute().timer();
+
+        let batch_size = clamp_to_remaining_fetch(self.batch_size, self.remaining_fetch);
+        let mut univariate_id_builder = UInt64Builder::with_capacity(batch_size);
+        let mut timestamp_builder = TimestampBuilder::with_capacity(batch_size);
+        let mut value_builder = ValueBuilder::with_capacity(batch_size);
+
+        // Repeatedly take the data point with the smallest (univariate_id, timestamp) key among
+        // the partitions that currently have a buffered data point until batch_size data points
+        // have been collected, the pushed down limit has been reached, or a partition that has run
+        // out of buffered data points is not yet ready to be refilled.
+        while univariate_id_builder.len() < batch_size {
+            let min_partition = self
+                .cursors
+                .iter()
+                .enumerate()
+                .filter(|(_, cursor)| cursor.offset < cursor.batch.num_rows())
+                .min_by_key(|(_, cursor)| Self::merge_key(&cursor.batch, cursor.offset))
+                .map(|(partition, _)| partition);
+
+            let Some(partition) = min_partition else {
+                break; // Every partition is exhausted.
+            };
+
+            let cursor = &mut self.cursors[partition];
+            let univariate_ids = modelardb_common::array!(cursor.batch, 0, UInt64Array);
+            let timestamps = modelardb_common::array!(cursor.batch, 1, TimestampArray);
+            let values = modelardb_common::array!(cursor.batch, 2, ValueArray);
+
+            univariate_id_builder.append_value(univariate_ids.value(cursor.offset));
+            timestamp_builder.append_value(timestamps.value(cursor.offset));
+            value_builder.append_value(values.value(cursor.offset));
+            cursor.offset += 1;
+
+            match Self::poll_refill_cursor(cursor, cx) {
+                Poll::Ready(Ok(())) => (),
+                Poll::Ready(Err(error)) => {
+                    return self
+                        .baseline_metrics
+                        .record_poll(Poll::Ready(Some(Err(error))))
+                }
+                // The partition just emptied by this iteration is not ready yet. Return the data
+                // points collected so far rather than blocking the entire merge on it.
+                Poll::Pending => break,
+            }
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(univariate_id_builder.finish()),
+            Arc::new(timestamp_builder.finish()),
+            Arc::new(value_builder.finish()),
+        ];
+
+        // unwrap() is safe as GridMergeStream uses a static schema.
+        let batch = RecordBatch::try_new(self.schema.clone(), columns).unwrap();
+
+        if let Some(remaining_fetch) = self.remaining_fetch.as_mut() {
+            *remaining_fetch -= batch.num_rows();
+        }
+
+        self.baseline_metrics
+            .record_poll(Poll::Ready(Some(Ok(batch))))
+    }
+}
+
+impl RecordBatchStream for GridMergeStream {
+    /// Return the schema of the stream.
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconstruction_budget_exhausted_without_limit() {
+        assert!(!reconstruction_budget_exhausted(0, None));
+        assert!(!reconstruction_budget_exhausted(1_000_000, None));
+    }
+
+    #[test]
+    fn test_reconstruction_budget_exhausted_under_and_at_limit() {
+        assert!(!reconstruction_budget_exhausted(0, Some(10)));
+        assert!(!reconstruction_budget_exhausted(10, Some(10)));
+    }
+
+    #[test]
+    fn test_reconstruction_budget_exhausted_over_limit() {
+        assert!(reconstruction_budget_exhausted(11, Some(10)));
+    }
+
+    #[test]
+    fn test_clamp_to_remaining_fetch_without_limit_is_noop() {
+        assert_eq!(clamp_to_remaining_fetch(100, None), 100);
+    }
+
+    #[test]
+    fn test_clamp_to_remaining_fetch_clamps_to_smaller_remaining_fetch() {
+        assert_eq!(clamp_to_remaining_fetch(100, Some(5)), 5);
+    }
+
+    #[test]
+    fn test_clamp_to_remaining_fetch_keeps_smaller_length() {
+        assert_eq!(clamp_to_remaining_fetch(5, Some(100)), 5);
+    }
+
+    #[test]
+    fn test_clamp_to_remaining_fetch_zero_remaining_fetch() {
+        assert_eq!(clamp_to_remaining_fetch(100, Some(0)), 0);
+    }
+
+    #[test]
+    fn test_record_reconstructed_segment_accumulates_totals() {
+        let metrics = ExecutionPlanMetricsSet::new();
+        let mut grid_metrics = GridMetrics::new(&metrics, 0);
+
+        grid_metrics.record_reconstructed_segment(1, 10, 100);
+        grid_metrics.record_reconstructed_segment(1, 5, 50);
+        grid_metrics.record_reconstructed_segment(2, 7, 70);
+
+        assert_eq!(grid_metrics.reconstructed_segments.value(), 3);
+        assert_eq!(grid_metrics.reconstructed_data_points.value(), 22);
+        assert_eq!(grid_metrics.compressed_bytes_read.value(), 220);
+        assert_eq!(grid_metrics.pruned_segments.value(), 0);
+    }
+
+    #[test]
+    fn test_record_reconstructed_segment_breaks_down_by_model_type() {
+        let metrics = ExecutionPlanMetricsSet::new();
+        let mut grid_metrics = GridMetrics::new(&metrics, 0);
+
+        grid_metrics.record_reconstructed_segment(1, 10, 100);
+        grid_metrics.record_reconstructed_segment(1, 5, 50);
+        grid_metrics.record_reconstructed_segment(2, 7, 70);
+
+        assert_eq!(grid_metrics.by_model_type.len(), 2);
+
+        let model_type_1 = &grid_metrics.by_model_type[&1];
+        assert_eq!(model_type_1.segments.value(), 2);
+        assert_eq!(model_type_1.data_points.value(), 15);
+
+        let model_type_2 = &grid_metrics.by_model_type[&2];
+        assert_eq!(model_type_2.segments.value(), 1);
+        assert_eq!(model_type_2.data_points.value(), 7);
+    }
+
+    #[test]
+    fn test_column_range_default_may_overlap_anything() {
+        let range = ColumnRange::default();
+        assert!(range.may_overlap(f64::MIN, f64::MAX));
+    }
+
+    #[test]
+    fn test_column_range_may_overlap_when_segment_entirely_inside() {
+        let mut range = ColumnRange::default();
+        range.tighten_lower(0.0);
+        range.tighten_upper(10.0);
+        assert!(range.may_overlap(2.0, 8.0));
+    }
+
+    #[test]
+    fn test_column_range_may_overlap_touching_at_lower_bound_is_overlap() {
+        let mut range = ColumnRange::default();
+        range.tighten_lower(5.0);
+        assert!(range.may_overlap(0.0, 5.0));
+    }
+
+    #[test]
+    fn test_column_range_may_overlap_touching_at_upper_bound_is_overlap() {
+        let mut range = ColumnRange::default();
+        range.tighten_upper(5.0);
+        assert!(range.may_overlap(5.0, 10.0));
+    }
+
+    #[test]
+    fn test_column_range_may_overlap_false_just_below_lower_bound() {
+        let mut range = ColumnRange::default();
+        range.tighten_lower(5.0);
+        assert!(!range.may_overlap(0.0, 4.999));
+    }
+
+    #[test]
+    fn test_column_range_may_overlap_false_just_above_upper_bound() {
+        let mut range = ColumnRange::default();
+        range.tighten_upper(5.0);
+        assert!(!range.may_overlap(5.001, 10.0));
+    }
+
+    #[test]
+    fn test_column_range_tighten_lower_only_narrows() {
+        let mut range = ColumnRange::default();
+        range.tighten_lower(5.0);
+        range.tighten_lower(1.0); // Less restrictive, must be ignored.
+        assert!(!range.may_overlap(0.0, 4.0));
+        range.tighten_lower(8.0); // More restrictive, must be applied.
+        assert!(!range.may_overlap(0.0, 7.0));
+    }
+
+    #[test]
+    fn test_column_range_tighten_upper_only_narrows() {
+        let mut range = ColumnRange::default();
+        range.tighten_upper(5.0);
+        range.tighten_upper(9.0); // Less restrictive, must be ignored.
+        assert!(!range.may_overlap(6.0, 10.0));
+        range.tighten_upper(2.0); // More restrictive, must be applied.
+        assert!(!range.may_overlap(3.0, 10.0));
+    }
+
+    /// Create a [`BinaryExpr`] comparing the column named `column_name` at `column_index` in
+    /// [`QUERY_SCHEMA`] to the literal `value` with `op`.
+    fn column_op_literal(
+        column_name: &str,
+        column_index: usize,
+        op: Operator,
+        value: f64,
+    ) -> Arc<dyn PhysicalExpr> {
+        Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(column_name, column_index)),
+            op,
+            Arc::new(Literal::new(ScalarValue::Float64(Some(value)))),
+        ))
+    }
+
+    #[test]
+    fn test_predicate_to_column_ranges_single_comparison_narrows_only_that_column() {
+        let predicate = column_op_literal("timestamp", 1, Operator::GtEq, 100.0);
+        let (time_range, value_range) = predicate_to_column_ranges(&predicate);
+
+        assert_eq!(time_range.lower, Some(100.0));
+        assert_eq!(time_range.upper, None);
+        assert_eq!(value_range.lower, None);
+        assert_eq!(value_range.upper, None);
+    }
+
+    #[test]
+    fn test_predicate_to_column_ranges_equality_sets_both_bounds() {
+        let predicate = column_op_literal("value", 2, Operator::Eq, 42.0);
+        let (_time_range, value_range) = predicate_to_column_ranges(&predicate);
+
+        assert_eq!(value_range.lower, Some(42.0));
+        assert_eq!(value_range.upper, Some(42.0));
+    }
+
+    #[test]
+    fn test_predicate_to_column_ranges_conjunction_narrows_both_columns() {
+        let time_predicate = column_op_literal("timestamp", 1, Operator::Lt, 200.0);
+        let value_predicate = column_op_literal("value", 2, Operator::GtEq, 0.0);
+        let predicate: Arc<dyn PhysicalExpr> = Arc::new(BinaryExpr::new(
+            time_predicate,
+            Operator::And,
+            value_predicate,
+        ));
+
+        let (time_range, value_range) = predicate_to_column_ranges(&predicate);
+
+        assert_eq!(time_range.upper, Some(200.0));
+        assert_eq!(value_range.lower, Some(0.0));
+    }
+
+    #[test]
+    fn test_predicate_to_column_ranges_literal_on_left_is_mirrored() {
+        // 100 <= timestamp is equivalent to timestamp >= 100.
+        let predicate: Arc<dyn PhysicalExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Literal::new(ScalarValue::Float64(Some(100.0)))),
+            Operator::LtEq,
+            Arc::new(Column::new("timestamp", 1)),
+        ));
+
+        let (time_range, _value_range) = predicate_to_column_ranges(&predicate);
+
+        assert_eq!(time_range.lower, Some(100.0));
+    }
+
+    #[test]
+    fn test_predicate_to_column_ranges_unrecognized_expression_does_not_narrow() {
+        // A comparison between two columns is not supported and must be ignored rather than
+        // incorrectly narrowing either range.
+        let predicate: Arc<dyn PhysicalExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("timestamp", 1)),
+            Operator::Gt,
+            Arc::new(Column::new("value", 2)),
+        ));
+
+        let (time_range, value_range) = predicate_to_column_ranges(&predicate);
+
+        assert_eq!(time_range.lower, None);
+        assert_eq!(time_range.upper, None);
+        assert_eq!(value_range.lower, None);
+        assert_eq!(value_range.upper, None);
+    }
+
+    /// Create a single [`QUERY_SCHEMA`] batch of data points from `rows` of
+    /// `(univariate_id, timestamp, value)`.
+    fn data_point_batch(rows: &[(u64, i64, f32)]) -> RecordBatch {
+        let mut univariate_id_builder = UInt64Builder::with_capacity(rows.len());
+        let mut timestamp_builder = TimestampBuilder::with_capacity(rows.len());
+        let mut value_builder = ValueBuilder::with_capacity(rows.len());
+
+        for (univariate_id, timestamp, value) in rows {
+            univariate_id_builder.append_value(*univariate_id);
+            timestamp_builder.append_value(*timestamp);
+            value_builder.append_value(*value);
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(univariate_id_builder.finish()),
+            Arc::new(timestamp_builder.finish()),
+            Arc::new(value_builder.finish()),
+        ];
+
+        RecordBatch::try_new(QUERY_SCHEMA.0.clone(), columns).unwrap()
+    }
+
+    /// Wrap `batches`, already in the order a partition's [`GridStream`] would produce them in, in
+    /// a [`SendableRecordBatchStream`] so they can be fed to [`GridMergeStream`] without going
+    /// through real segment reconstruction.
+    fn partition_stream(batches: Vec<RecordBatch>) -> SendableRecordBatchStream {
+        Box::pin(
+            datafusion::physical_plan::stream::RecordBatchStreamAdapter::new(
+                QUERY_SCHEMA.0.clone(),
+                futures::stream::iter(batches.into_iter().map(Ok)),
+            ),
+        )
+    }
+
+    /// Create a [`BaselineMetrics`] not tied to any real [`ExecutionPlanMetricsSet`], for tests
+    /// that do not assert on metrics.
+    fn test_baseline_metrics() -> BaselineMetrics {
+        BaselineMetrics::new(&ExecutionPlanMetricsSet::new(), 0)
+    }
+
+    /// Drive `stream` to completion and return the `(univariate_id, timestamp)` of every data
+    /// point it produces, in the order produced.
+    fn collect_merge_keys(stream: GridMergeStream) -> Vec<(u64, i64)> {
+        let batches: Vec<RecordBatch> = futures::executor::block_on(stream.collect::<Vec<_>>())
+            .into_iter()
+            .map(|batch| batch.unwrap())
+            .collect();
+
+        batches
+            .iter()
+            .flat_map(|batch| {
+                (0..batch.num_rows()).map(|row| GridMergeStream::merge_key(batch, row))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_grid_merge_stream_merges_partitions_in_sorted_order() {
+        let partition_0 = partition_stream(vec![data_point_batch(&[(1, 0, 1.0), (1, 20, 2.0)])]);
+        let partition_1 = partition_stream(vec![data_point_batch(&[(1, 10, 3.0), (2, 0, 4.0)])]);
+
+        let stream = GridMergeStream::new(
+            QUERY_SCHEMA.0.clone(),
+            vec![partition_0, partition_1],
+            10,
+            None,
+            test_baseline_metrics(),
+        );
+        let keys = collect_merge_keys(stream);
+
+        assert_eq!(keys, vec![(1, 0), (1, 10), (1, 20), (2, 0)]);
+    }
+
+    #[test]
+    fn test_grid_merge_stream_without_limit_returns_every_row() {
+        let partition_0 = partition_stream(vec![data_point_batch(&[(1, 0, 1.0), (1, 1, 2.0)])]);
+        let partition_1 = partition_stream(vec![data_point_batch(&[(2, 0, 3.0), (3, 0, 4.0)])]);
+
+        let stream = GridMergeStream::new(
+            QUERY_SCHEMA.0.clone(),
+            vec![partition_0, partition_1],
+            10,
+            None,
+            test_baseline_metrics(),
+        );
+        let keys = collect_merge_keys(stream);
+
+        assert_eq!(keys.len(), 4);
+    }
+
+    #[test]
+    fn test_grid_merge_stream_respects_pushed_down_limit_across_partitions() {
+        // Each partition has more rows than the limit on its own, reproducing the case where
+        // reconstructing every partition's GridStream in full would do far more work than needed.
+        let partition_0 = partition_stream(vec![data_point_batch(&[
+            (1, 0, 1.0),
+            (1, 10, 2.0),
+            (1, 20, 3.0),
+        ])]);
+        let partition_1 = partition_stream(vec![data_point_batch(&[
+            (2, 0, 4.0),
+            (2, 10, 5.0),
+            (2, 20, 6.0),
+        ])]);
+
+        let stream = GridMergeStream::new(
+            QUERY_SCHEMA.0.clone(),
+            vec![partition_0, partition_1],
+            10,
+            Some(2),
+            test_baseline_metrics(),
+        );
+        let keys = collect_merge_keys(stream);
+
+        // The two globally smallest (univariate_id, timestamp) keys both come from partition 0.
+        assert_eq!(keys, vec![(1, 0), (1, 10)]);
+    }
+
+    #[test]
+    fn test_grid_merge_stream_limit_of_zero_returns_no_rows() {
+        let partition_0 = partition_stream(vec![data_point_batch(&[(1, 0, 1.0)])]);
+
+        let stream = GridMergeStream::new(
+            QUERY_SCHEMA.0.clone(),
+            vec![partition_0],
+            10,
+            Some(0),
+            test_baseline_metrics(),
+        );
+        let keys = collect_merge_keys(stream);
+
+        assert!(keys.is_empty());
+    }
+}